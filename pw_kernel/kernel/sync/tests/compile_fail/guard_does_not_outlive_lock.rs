@@ -0,0 +1,25 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! The guard returned by `SpinLock::lock` borrows from the lock; it must
+//! not be usable once the lock it came from has been dropped.
+use spinlock::SpinLock;
+
+fn main() {
+    let guard;
+    {
+        let lock = SpinLock::new(0);
+        guard = lock.lock();
+    }
+    let _value = *guard;
+}
@@ -0,0 +1,26 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Drives `unittest_compile_fail` over the fixtures in `tests/compile_fail`,
+//! asserting that spinlock guard misuse (e.g. a guard outliving its lock)
+//! is rejected by the compiler rather than only at runtime.
+//!
+//! This is host tooling, not a kernel test: it shells out to `rustc`, so it
+//! runs with the regular built-in test harness rather than `unittest`.
+use std::path::Path;
+
+#[test]
+fn guard_misuse_is_rejected_at_compile_time() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+    unittest_compile_fail::check_all(&fixtures).unwrap();
+}
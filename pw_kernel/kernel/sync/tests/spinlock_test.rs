@@ -46,3 +46,15 @@ fn try_lock_returns_correct_value() -> unittest::Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+fn double_lock_via_try_lock_unwrap_panics() {
+    let lock = BareSpinLock::new();
+    let _sentinel = lock.lock();
+
+    // The lock is already held, so `try_lock()` returns `None` and the
+    // `unwrap()` below panics instead of silently aliasing the critical
+    // section.
+    lock.try_lock().unwrap();
+}
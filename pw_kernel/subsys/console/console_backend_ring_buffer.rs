@@ -0,0 +1,90 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! An in-memory [`ConsoleBackend`] that captures writes into a fixed-size
+//! ring buffer instead of emitting them anywhere, so unit tests can assert
+//! on console output without a real sink.
+#![no_std]
+
+use pw_status::Result;
+use spinlock::SpinLock;
+
+use console_registry::ConsoleBackend;
+
+struct Ring<const N: usize> {
+    data: [u8; N],
+    // Total bytes ever written; once `written` exceeds `N`, the live contents
+    // are the `N` bytes at positions `written - N..written`, taken mod `N`.
+    written: usize,
+}
+
+impl<const N: usize> Ring<N> {
+    const fn new() -> Self {
+        Self {
+            data: [0; N],
+            written: 0,
+        }
+    }
+}
+
+/// A fixed-capacity ring buffer that retains only the most recent `N` bytes
+/// written to it.
+pub struct RingBufferBackend<const N: usize> {
+    ring: SpinLock<Ring<N>>,
+}
+
+impl<const N: usize> RingBufferBackend<N> {
+    pub const fn new() -> Self {
+        Self {
+            ring: SpinLock::new(Ring::new()),
+        }
+    }
+
+    /// Copies the currently retained bytes, oldest first, into `out` and
+    /// returns the number of bytes copied.
+    ///
+    /// If `out` is smaller than the retained content, the bytes returned
+    /// are the oldest ones still retained, not the most recent.
+    pub fn read(&self, out: &mut [u8]) -> usize {
+        let ring = self.ring.lock();
+        let retained_len = ring.written.min(N);
+        let retained_start = ring.written - retained_len;
+        let len = retained_len.min(out.len());
+        for i in 0..len {
+            out[i] = ring.data[(retained_start + i) % N];
+        }
+        len
+    }
+
+    /// Drops all captured content.
+    pub fn clear(&self) {
+        let mut ring = self.ring.lock();
+        ring.written = 0;
+    }
+}
+
+impl<const N: usize> ConsoleBackend for RingBufferBackend<N> {
+    fn write(&self, buf: &[u8]) -> Result<usize> {
+        let mut ring = self.ring.lock();
+        for &byte in buf {
+            let idx = ring.written % N;
+            ring.data[idx] = byte;
+            ring.written += 1;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -16,14 +16,24 @@
 use cortex_m_semihosting::hio::hstdout;
 use pw_status::{Error, Result};
 
-#[no_mangle]
-pub fn console_backend_write(buf: &[u8]) -> Result<usize> {
-    let mut stdout = hstdout().map_err(|_| Error::Unavailable)?;
-    stdout.write_all(buf).map_err(|_| Error::DataLoss)?;
-    Ok(buf.len())
-}
+use console_registry::{ConsoleBackend, LinkedBackend};
+
+pub struct SemihostingConsole;
 
-#[no_mangle]
-pub fn console_backend_flush() -> Result<()> {
-    Ok(())
+impl ConsoleBackend for SemihostingConsole {
+    fn write(&self, buf: &[u8]) -> Result<usize> {
+        let mut stdout = hstdout().map_err(|_| Error::Unavailable)?;
+        stdout.write_all(buf).map_err(|_| Error::DataLoss)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&self) -> Result<()> {
+        Ok(())
+    }
 }
+
+/// Registers [`SemihostingConsole`] as a default console sink by linking,
+/// with no startup call required: see `console_registry`'s module docs.
+#[used]
+#[link_section = "console_backends"]
+static DEFAULT_BACKEND: LinkedBackend = &SemihostingConsole;
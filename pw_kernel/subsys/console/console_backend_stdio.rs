@@ -14,12 +14,22 @@
 use pw_status::{Error, Result};
 use std::io::{stdout, Write as StdWrite};
 
-#[no_mangle]
-pub fn console_backend_write(buf: &[u8]) -> Result<usize> {
-    stdout().lock().write(buf).map_err(|_| Error::Unknown)
-}
+use console_registry::{ConsoleBackend, LinkedBackend};
+
+pub struct StdioConsole;
 
-#[no_mangle]
-pub fn console_backend_flush() -> Result<()> {
-    stdout().lock().flush().map_err(|_| Error::Unknown)
+impl ConsoleBackend for StdioConsole {
+    fn write(&self, buf: &[u8]) -> Result<usize> {
+        stdout().lock().write(buf).map_err(|_| Error::Unknown)
+    }
+
+    fn flush(&self) -> Result<()> {
+        stdout().lock().flush().map_err(|_| Error::Unknown)
+    }
 }
+
+/// Registers [`StdioConsole`] as a default console sink by linking, with no
+/// startup call required: see `console_registry`'s module docs.
+#[used]
+#[link_section = "console_backends"]
+static DEFAULT_BACKEND: LinkedBackend = &StdioConsole;
@@ -0,0 +1,124 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Allocation-free numeric formatting for the console backends.
+//!
+//! `core::fmt` is general purpose and pulls in a fair amount of code size to
+//! support it. Logging a handful of integers and floats doesn't need that
+//! machinery, so this module formats straight into a small stack buffer and
+//! hands the resulting slice to `console_backend_write`.
+#![no_std]
+
+use pw_status::Result;
+
+extern "Rust" {
+    fn console_backend_write(buf: &[u8]) -> Result<usize>;
+}
+
+/// Two decimal digits per entry, e.g. `DIGIT_PAIRS[2 * 42..2 * 42 + 2] == b"42"`.
+const DIGIT_PAIRS: &[u8; 200] = b"00010203040506070809101112131415161718192021222324252627282930313233343536373839404142434445464748495051525354555657585960616263646566676869707172737475767778798081828384858687888990919293949596979899";
+
+/// Formats `value` into the tail of `buf` and returns the occupied suffix.
+fn format_u64(mut value: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut pos = buf.len();
+
+    while value >= 10000 {
+        let rem = (value % 10000) as usize;
+        value /= 10000;
+        let (hi, lo) = (rem / 100, rem % 100);
+        pos -= 2;
+        buf[pos..pos + 2].copy_from_slice(&DIGIT_PAIRS[2 * lo..2 * lo + 2]);
+        pos -= 2;
+        buf[pos..pos + 2].copy_from_slice(&DIGIT_PAIRS[2 * hi..2 * hi + 2]);
+    }
+
+    let mut rem = value as usize;
+    loop {
+        pos -= 1;
+        buf[pos] = b'0' + (rem % 10) as u8;
+        rem /= 10;
+        if rem == 0 {
+            break;
+        }
+    }
+
+    &buf[pos..]
+}
+
+/// Writes the decimal representation of `value` to the console.
+pub fn write_u64(value: u64) -> Result<usize> {
+    let mut buf = [0u8; 20];
+    let digits = format_u64(value, &mut buf);
+    unsafe { console_backend_write(digits) }
+}
+
+/// Writes the decimal representation of `value` to the console.
+pub fn write_i64(value: i64) -> Result<usize> {
+    if value >= 0 {
+        return write_u64(value as u64);
+    }
+
+    let mut digits_buf = [0u8; 20];
+    let digits = format_u64(value.unsigned_abs(), &mut digits_buf);
+
+    let mut buf = [0u8; 21];
+    buf[0] = b'-';
+    buf[1..1 + digits.len()].copy_from_slice(digits);
+    unsafe { console_backend_write(&buf[..1 + digits.len()]) }
+}
+
+/// Writes `value` to the console with a fixed number of fractional digits.
+///
+/// This is not a general-purpose float formatter: it truncates (rather than
+/// rounds) to `PRECISION` fractional digits. `NaN` and the infinities are
+/// special-cased to fixed strings, since they have no meaningful integer or
+/// fractional part to truncate. That is sufficient for embedded logging,
+/// where a few stable digits of precision matter far more than exact
+/// `core::fmt` semantics.
+pub fn write_f64(value: f64) -> Result<usize> {
+    const PRECISION: u32 = 6;
+    const SCALE: f64 = 1_000_000.0; // 10^PRECISION
+
+    if value.is_nan() {
+        return unsafe { console_backend_write(b"NaN") };
+    }
+    if value.is_infinite() {
+        let sign: &[u8] = if value.is_sign_negative() { b"-inf" } else { b"inf" };
+        return unsafe { console_backend_write(sign) };
+    }
+
+    let negative = value.is_sign_negative();
+    let magnitude = value.abs();
+    let int_part = magnitude as u64;
+    let frac_part = (((magnitude - int_part as f64) * SCALE) as u64).min(SCALE as u64 - 1);
+
+    let mut int_buf = [0u8; 20];
+    let int_digits = format_u64(int_part, &mut int_buf);
+    let mut frac_buf = [0u8; 20];
+    let frac_digits = format_u64(frac_part, &mut frac_buf);
+
+    let mut written = 0;
+    if negative {
+        written += unsafe { console_backend_write(b"-")? };
+    }
+    written += unsafe { console_backend_write(int_digits)? };
+    written += unsafe { console_backend_write(b".")? };
+
+    let leading_zeros = PRECISION as usize - frac_digits.len();
+    for _ in 0..leading_zeros {
+        written += unsafe { console_backend_write(b"0")? };
+    }
+    written += unsafe { console_backend_write(frac_digits)? };
+
+    Ok(written)
+}
@@ -0,0 +1,90 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! An optional [`ConsoleBackend`] wrapper that coalesces small writes into
+//! whole lines before handing them to the wrapped backend, trading a little
+//! latency for fewer, larger writes.
+#![no_std]
+
+use pw_status::{Error, Result};
+use spinlock::SpinLock;
+
+use console_registry::ConsoleBackend;
+
+/// Size of the internal coalescing buffer. When it is full, its contents are
+/// flushed to the wrapped backend before the next byte is buffered, so a
+/// long write reaches the backend in buffer-sized pieces.
+const BUFFER_SIZE: usize = 128;
+
+struct Buffer {
+    data: [u8; BUFFER_SIZE],
+    len: usize,
+}
+
+impl Buffer {
+    const fn new() -> Self {
+        Self {
+            data: [0; BUFFER_SIZE],
+            len: 0,
+        }
+    }
+}
+
+/// Wraps `backend`, buffering writes until a newline is seen or the
+/// internal buffer fills, then flushing the coalesced line in one call.
+pub struct LineBuffered<B: ConsoleBackend> {
+    backend: B,
+    buffer: SpinLock<Buffer>,
+}
+
+impl<B: ConsoleBackend> LineBuffered<B> {
+    pub const fn new(backend: B) -> Self {
+        Self {
+            backend,
+            buffer: SpinLock::new(Buffer::new()),
+        }
+    }
+
+    fn flush_locked(&self, buffer: &mut Buffer) -> Result<()> {
+        if buffer.len == 0 {
+            return Ok(());
+        }
+        self.backend.write(&buffer.data[..buffer.len])?;
+        buffer.len = 0;
+        self.backend.flush()
+    }
+}
+
+impl<B: ConsoleBackend> ConsoleBackend for LineBuffered<B> {
+    fn write(&self, buf: &[u8]) -> Result<usize> {
+        let mut buffer = self.buffer.lock();
+
+        for &byte in buf {
+            if buffer.len == BUFFER_SIZE {
+                self.flush_locked(&mut buffer).map_err(|_| Error::DataLoss)?;
+            }
+            buffer.data[buffer.len] = byte;
+            buffer.len += 1;
+            if byte == b'\n' {
+                self.flush_locked(&mut buffer).map_err(|_| Error::DataLoss)?;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&self) -> Result<()> {
+        let mut buffer = self.buffer.lock();
+        self.flush_locked(&mut buffer)
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! A [`ConsoleBackend`] trait plus a small static registry that fans console
+//! writes out to every registered sink (e.g. semihosting alongside an
+//! in-memory ring buffer for test capture).
+//!
+//! Backends reach the registry one of two ways:
+//!
+//! - Linked in statically, by placing a `&'static dyn ConsoleBackend` in the
+//!   `console_backends` link section (see [`LinkedBackend`]). This is how
+//!   the per-target default sink (stdio, semihosting, ...) reaches
+//!   [`write_all`]/[`flush_all`] with no startup code required — exactly
+//!   like the old `#[no_mangle]` seam, output "just works" once the target
+//!   crate is linked in.
+//! - Registered at runtime with [`register_backend`], for sinks that are
+//!   constructed dynamically, such as a test-local ring buffer.
+#![no_std]
+
+use pw_status::Result;
+use spinlock::SpinLock;
+
+/// A console output sink.
+///
+/// Implementations reach the registry either by linking (see the module
+/// docs) or by calling [`register_backend`] at runtime. A single write then
+/// fans out to every backend reached either way, instead of being tied to
+/// exactly one target-specific sink.
+pub trait ConsoleBackend: Sync {
+    fn write(&self, buf: &[u8]) -> Result<usize>;
+    fn flush(&self) -> Result<()>;
+}
+
+/// A statically linked backend entry. Target crates that provide the
+/// default console sink place one of these in the `console_backends` link
+/// section instead of calling [`register_backend`], so output works as
+/// soon as the crate is linked in, with no startup call required.
+pub type LinkedBackend = &'static dyn ConsoleBackend;
+
+extern "Rust" {
+    #[link_name = "__start_console_backends"]
+    static START_LINKED_BACKENDS: LinkedBackend;
+    #[link_name = "__stop_console_backends"]
+    static STOP_LINKED_BACKENDS: LinkedBackend;
+}
+
+/// Returns every backend reached via the `console_backends` link section.
+///
+/// # Safety
+///
+/// Relies on the linker keeping every `#[link_section = "console_backends"]`
+/// static contiguous between the `__start_console_backends`/
+/// `__stop_console_backends` boundary symbols, which `#[used]` guarantees
+/// are retained.
+fn linked_backends() -> &'static [LinkedBackend] {
+    unsafe {
+        let start = &START_LINKED_BACKENDS as *const LinkedBackend;
+        let stop = &STOP_LINKED_BACKENDS as *const LinkedBackend;
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    }
+}
+
+/// Upper bound on concurrently registered backends. Kept small and static
+/// so registration needs no heap.
+const MAX_BACKENDS: usize = 4;
+
+struct Registry {
+    backends: [Option<&'static dyn ConsoleBackend>; MAX_BACKENDS],
+    len: usize,
+}
+
+impl Registry {
+    const fn new() -> Self {
+        Self {
+            backends: [None; MAX_BACKENDS],
+            len: 0,
+        }
+    }
+}
+
+static REGISTRY: SpinLock<Registry> = SpinLock::new(Registry::new());
+
+/// Registers `backend` to receive all future console writes and flushes.
+///
+/// # Panics
+///
+/// Panics if more than `MAX_BACKENDS` backends are registered.
+pub fn register_backend(backend: &'static dyn ConsoleBackend) {
+    let mut registry = REGISTRY.lock();
+    assert!(
+        registry.len < MAX_BACKENDS,
+        "too many console backends registered"
+    );
+    let len = registry.len;
+    registry.backends[len] = Some(backend);
+    registry.len += 1;
+}
+
+/// Writes `buf` to every linked and every registered backend.
+///
+/// All backends are attempted even if one fails; the first error
+/// encountered, if any, is returned.
+pub fn write_all(buf: &[u8]) -> Result<usize> {
+    let registry = REGISTRY.lock();
+    let mut result = Ok(buf.len());
+    for backend in linked_backends()
+        .iter()
+        .copied()
+        .chain(registry.backends[..registry.len].iter().flatten().copied())
+    {
+        if let Err(e) = backend.write(buf) {
+            if result.is_ok() {
+                result = Err(e);
+            }
+        }
+    }
+    result
+}
+
+/// Flushes every linked and every registered backend, as above attempting
+/// all of them.
+pub fn flush_all() -> Result<()> {
+    let registry = REGISTRY.lock();
+    let mut result = Ok(());
+    for backend in linked_backends()
+        .iter()
+        .copied()
+        .chain(registry.backends[..registry.len].iter().flatten().copied())
+    {
+        if let Err(e) = backend.flush() {
+            if result.is_ok() {
+                result = Err(e);
+            }
+        }
+    }
+    result
+}
+
+/// Thin default adapter preserving the original `#[no_mangle]` seam: code
+/// that still links against `console_backend_write`/`console_backend_flush`
+/// directly, rather than going through [`register_backend`], keeps working.
+#[no_mangle]
+pub fn console_backend_write(buf: &[u8]) -> Result<usize> {
+    write_all(buf)
+}
+
+#[no_mangle]
+pub fn console_backend_flush() -> Result<()> {
+    flush_all()
+}
@@ -0,0 +1,56 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Pluggable global allocator, mirroring the `console_backend` seam.
+//!
+//! Targets externalize heap allocation the same way the console crate
+//! externalizes its sink: this crate declares the `heap_backend_alloc`/
+//! `heap_backend_dealloc` symbols as `extern "Rust"` and forwards to whatever
+//! backend the target links in (a fixed-region allocator on `#![no_std]`,
+//! the system allocator under `std`).
+#![no_std]
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+extern "Rust" {
+    fn heap_backend_alloc(size: usize, align: usize) -> *mut u8;
+    fn heap_backend_dealloc(ptr: *mut u8, size: usize, align: usize);
+}
+
+/// Count of allocations made through [`HeapBackendAllocator`] that have not
+/// yet been balanced by a matching deallocation.
+///
+/// Exposed so leak tests can assert it returns to zero at the end of a test.
+pub static HITS: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`GlobalAlloc`] that forwards to the linked `heap_backend_*` symbols.
+pub struct HeapBackendAllocator;
+
+unsafe impl GlobalAlloc for HeapBackendAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = heap_backend_alloc(layout.size(), layout.align());
+        if !ptr.is_null() {
+            HITS.fetch_add(1, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        HITS.fetch_sub(1, Ordering::Relaxed);
+        heap_backend_dealloc(ptr, layout.size(), layout.align())
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: HeapBackendAllocator = HeapBackendAllocator;
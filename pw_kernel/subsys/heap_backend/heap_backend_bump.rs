@@ -0,0 +1,54 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Reference `heap_backend` for `#![no_std]` targets: a bump allocator over
+//! a fixed, statically sized arena. It never reclaims memory, which keeps it
+//! trivially correct for targets that only need to bound worst-case
+//! allocation rather than recycle it.
+#![no_std]
+
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Size of the static arena backing allocations on this target.
+const ARENA_SIZE: usize = 64 * 1024;
+
+static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+#[no_mangle]
+pub unsafe fn heap_backend_alloc(size: usize, align: usize) -> *mut u8 {
+    let arena_base = ptr::addr_of_mut!(ARENA).cast::<u8>();
+
+    loop {
+        let current = NEXT.load(Ordering::Relaxed);
+        let candidate = arena_base.add(current);
+        let aligned_offset = current + candidate.align_offset(align);
+        let end = aligned_offset + size;
+        if end > ARENA_SIZE {
+            return ptr::null_mut();
+        }
+
+        if NEXT
+            .compare_exchange(current, end, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            return arena_base.add(aligned_offset);
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe fn heap_backend_dealloc(_ptr: *mut u8, _size: usize, _align: usize) {
+    // Bump allocator: individual allocations are never reclaimed.
+}
@@ -0,0 +1,30 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! `heap_backend` for host builds: forwards to the system allocator.
+//!
+//! This calls `System` directly rather than the `std::alloc::{alloc,
+//! dealloc}` free functions, which dispatch to the process's registered
+//! `#[global_allocator]` — which is the `HeapBackendAllocator` that in turn
+//! calls these functions, recursing forever.
+use std::alloc::{GlobalAlloc, Layout, System};
+
+#[no_mangle]
+pub unsafe fn heap_backend_alloc(size: usize, align: usize) -> *mut u8 {
+    System.alloc(Layout::from_size_align_unchecked(size, align))
+}
+
+#[no_mangle]
+pub unsafe fn heap_backend_dealloc(ptr: *mut u8, size: usize, align: usize) {
+    System.dealloc(ptr, Layout::from_size_align_unchecked(size, align))
+}
@@ -0,0 +1,184 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Minimal unit test framework for `#![no_std]` kernel code.
+//!
+//! A test is a function marked `#[test]`, in one of two forms:
+//!
+//! - returning `unittest::Result<()>`, where `Ok(())` passes, or
+//! - annotated `#[should_panic]` (optionally `#[should_panic(expected =
+//!   "...")]`), where the function is expected to panic rather than return.
+//!
+//! `#[test]` registers each function into the `unittest_tests` link
+//! section; [`test_cases`] walks that section so a target-specific runner
+//! can execute every test without a build-time-generated list. A
+//! `should_panic` test that actually panics never returns to [`run`] — on
+//! `panic = abort` targets the panic is terminal for the whole binary, so
+//! the verdict is decided inside the panic handler via [`handle_panic`]
+//! instead.
+#![no_std]
+
+use core::panic::PanicInfo;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use spinlock::SpinLock;
+
+pub use unittest_macro::test;
+
+/// Why a test failed.
+#[derive(Debug)]
+pub struct Failure {
+    pub message: &'static str,
+}
+
+pub type Result<T> = core::result::Result<T, Failure>;
+
+#[macro_export]
+macro_rules! assert_true {
+    ($cond:expr) => {
+        if !($cond) {
+            return ::core::result::Result::Err($crate::Failure {
+                message: ::core::concat!("assertion failed: ", ::core::stringify!($cond)),
+            });
+        }
+    };
+}
+
+/// How a `#[test]`-annotated function is expected to terminate.
+pub enum Expectation {
+    /// The function returns `Result<()>`; `Ok(())` passes.
+    Returns(fn() -> Result<()>),
+    /// The function is expected to panic. On success `run` never returns:
+    /// the panic handler observes the panic via [`handle_panic`] and
+    /// reports the verdict itself before halting.
+    Panics {
+        expected: Option<&'static str>,
+        run: fn(),
+    },
+}
+
+/// A single test registered by `#[test]`.
+pub struct TestCase {
+    pub name: &'static str,
+    pub expectation: Expectation,
+}
+
+extern "Rust" {
+    #[link_name = "__start_unittest_tests"]
+    static START_TESTS: TestCase;
+    #[link_name = "__stop_unittest_tests"]
+    static STOP_TESTS: TestCase;
+}
+
+/// Returns every test registered via `#[test]`, in link order.
+///
+/// # Safety
+///
+/// Relies on the linker keeping every `#[link_section = "unittest_tests"]`
+/// static contiguous between the `__start_unittest_tests`/
+/// `__stop_unittest_tests` boundary symbols, which `#[used]` guarantees are
+/// retained.
+pub fn test_cases() -> &'static [TestCase] {
+    unsafe {
+        let start = &START_TESTS as *const TestCase;
+        let stop = &STOP_TESTS as *const TestCase;
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    }
+}
+
+/// The result of running a single [`TestCase`] that returned normally.
+///
+/// A `should_panic` test that actually panics is reported by
+/// [`handle_panic`] instead, since control never returns here in that case.
+pub enum Outcome {
+    Passed,
+    Failed(Failure),
+    /// A `should_panic` test ran to completion without panicking.
+    DidNotPanic,
+}
+
+static EXPECTED_PANIC_MESSAGE: SpinLock<Option<&'static str>> = SpinLock::new(None);
+static IN_SHOULD_PANIC_TEST: AtomicBool = AtomicBool::new(false);
+
+/// Runs `case`, returning its outcome if it returns normally.
+pub fn run(case: &TestCase) -> Outcome {
+    match case.expectation {
+        Expectation::Returns(run) => match run() {
+            Ok(()) => Outcome::Passed,
+            Err(failure) => Outcome::Failed(failure),
+        },
+        Expectation::Panics { expected, run } => {
+            *EXPECTED_PANIC_MESSAGE.lock() = expected;
+            IN_SHOULD_PANIC_TEST.store(true, Ordering::SeqCst);
+
+            run();
+
+            IN_SHOULD_PANIC_TEST.store(false, Ordering::SeqCst);
+            Outcome::DidNotPanic
+        }
+    }
+}
+
+/// Called by the target's `#[panic_handler]` before it halts.
+///
+/// Returns `true` when the panic happened inside a currently running
+/// `should_panic` test and, if an `expected` substring was given, the panic
+/// message contains it — meaning the caller should report that test as
+/// passing instead of treating the panic as a crash.
+pub fn handle_panic(info: &PanicInfo) -> bool {
+    if !IN_SHOULD_PANIC_TEST.load(Ordering::SeqCst) {
+        return false;
+    }
+
+    match *EXPECTED_PANIC_MESSAGE.lock() {
+        None => true,
+        Some(expected) => {
+            let mut buf = [0u8; 256];
+            let message = format_panic_message(info, &mut buf);
+            contains(message, expected.as_bytes())
+        }
+    }
+}
+
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl core::fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let bytes = s.as_bytes();
+        let remaining = self.buf.len() - self.len;
+        let n = remaining.min(bytes.len());
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+fn format_panic_message<'a>(info: &PanicInfo, buf: &'a mut [u8]) -> &'a [u8] {
+    use core::fmt::Write;
+    let mut writer = SliceWriter { buf, len: 0 };
+    let _ = write!(writer, "{}", info.message());
+    &writer.buf[..writer.len]
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    if needle.is_empty() {
+        return true;
+    }
+    if needle.len() > haystack.len() {
+        return false;
+    }
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
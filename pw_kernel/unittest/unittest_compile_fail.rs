@@ -0,0 +1,82 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Host-side harness for compile-fail fixtures.
+//!
+//! Each fixture is a `<name>.rs` file paired with a `<name>.stderr` file
+//! listing (one per line) substrings that must appear in `rustc`'s
+//! diagnostics for it. This is host tooling, not a `#![no_std]` test: it
+//! shells out to `rustc` and inspects process output, so it runs wherever
+//! the crate's own tests run rather than on the target.
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Compiles `fixture` and checks that it fails to compile with every line
+/// recorded in its sibling `<fixture>.stderr` file present in `rustc`'s
+/// stderr.
+pub fn check(fixture: &Path) -> core::result::Result<(), String> {
+    let expected_path = fixture.with_extension("stderr");
+    let expected = fs::read_to_string(&expected_path).map_err(|e| {
+        format!(
+            "{}: missing expectation file {}: {e}",
+            fixture.display(),
+            expected_path.display()
+        )
+    })?;
+
+    let output = Command::new("rustc")
+        .arg("--edition=2021")
+        .arg("--crate-type=lib")
+        .arg("--emit=metadata")
+        .arg("-o")
+        .arg(std::env::temp_dir().join("unittest_compile_fail_fixture.rmeta"))
+        .arg(fixture)
+        .output()
+        .map_err(|e| format!("{}: failed to invoke rustc: {e}", fixture.display()))?;
+
+    if output.status.success() {
+        return Err(format!(
+            "{}: expected a compile error, but it compiled successfully",
+            fixture.display()
+        ));
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for expected_line in expected.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if !stderr.contains(expected_line) {
+            return Err(format!(
+                "{}: expected stderr to contain {expected_line:?}, got:\n{stderr}",
+                fixture.display()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs [`check`] against every `*.rs` fixture directly inside `dir`.
+pub fn check_all(dir: &Path) -> core::result::Result<(), String> {
+    let mut fixtures: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("{}: {e}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    fixtures.sort();
+
+    for fixture in fixtures {
+        check(&fixture)?;
+    }
+    Ok(())
+}
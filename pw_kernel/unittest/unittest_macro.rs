@@ -0,0 +1,84 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+//! Proc-macro implementation backing `unittest`'s `#[test]` attribute.
+//!
+//! `test` is the only attribute macro this crate registers: it parses the
+//! annotated function, looks for (and strips) a `#[should_panic]` attribute
+//! on it, and emits a [`unittest::TestCase`] static describing how the
+//! function is expected to terminate. Handling `should_panic` here, rather
+//! than as its own attribute macro, sidesteps the ordering question of two
+//! stacked attribute macros on one item entirely.
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, ExprLit, ItemFn, Lit, MetaNameValue, parse_macro_input};
+
+/// Finds, removes, and interprets a `#[should_panic]` or
+/// `#[should_panic(expected = "...")]` attribute on `item_fn`.
+///
+/// Returns `None` if there is no such attribute; `Some(None)` for a bare
+/// `#[should_panic]`; `Some(Some(message))` when an `expected` substring was
+/// given.
+fn take_should_panic(item_fn: &mut ItemFn) -> Option<Option<String>> {
+    let index = item_fn
+        .attrs
+        .iter()
+        .position(|attr| attr.path().is_ident("should_panic"))?;
+    let attr = item_fn.attrs.remove(index);
+
+    let expected = attr.parse_args::<MetaNameValue>().ok().and_then(|nv| {
+        match nv.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Some(s.value()),
+            _ => None,
+        }
+    });
+    Some(expected)
+}
+
+#[proc_macro_attribute]
+pub fn test(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut item_fn = parse_macro_input!(item as ItemFn);
+    let should_panic = take_should_panic(&mut item_fn);
+    let fn_name = item_fn.sig.ident.clone();
+    let case_name = format_ident!("__UNITTEST_CASE_{}", fn_name.to_string().to_uppercase());
+
+    let expectation = match should_panic {
+        Some(expected) => {
+            let expected_tokens = match expected {
+                Some(message) => quote! { ::core::option::Option::Some(#message) },
+                None => quote! { ::core::option::Option::None },
+            };
+            quote! {
+                ::unittest::Expectation::Panics {
+                    expected: #expected_tokens,
+                    run: #fn_name,
+                }
+            }
+        }
+        None => quote! { ::unittest::Expectation::Returns(#fn_name) },
+    };
+
+    quote! {
+        #item_fn
+
+        #[used]
+        #[link_section = "unittest_tests"]
+        static #case_name: ::unittest::TestCase = ::unittest::TestCase {
+            name: ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#fn_name)),
+            expectation: #expectation,
+        };
+    }
+    .into()
+}